@@ -0,0 +1,18 @@
+macro_rules! token_type {
+    ("space") => { ' ' | '\t' | '\r' | '\n' };
+    ("digit") => { '0'..='9' };
+    ("alpha") => { 'a'..='z' | 'A'..='Z' | '_' };
+    ("alphanumeric") => { 'a'..='z' | 'A'..='Z' | '0'..='9' | '_' };
+    ("bracket") => { '(' | ')' | '[' | ']' | '{' | '}' };
+    ("cmp_operator") => { '<' | '>' | '=' | '!' };
+    ("operator") => { '+' | '-' | '*' | '/' | '%' };
+    ("question_mark") => { '?' };
+}
+
+macro_rules! is_token_type {
+    ($c:expr, $t:tt) => {
+        matches!($c, $crate::lexer::codes::token_type!($t))
+    };
+}
+
+pub(crate) use {is_token_type, token_type};
@@ -0,0 +1,158 @@
+use crate::lexer::position::Position;
+use std::str::CharIndices;
+
+pub(crate) type CursorItem = (usize, char);
+
+/// Walks a source string one `char` at a time, tracking both the flat byte
+/// offset (so existing offset-based spans keep working) and the `line`/
+/// `column` it corresponds to. `\r\n` is treated as a single line break: the
+/// line counter advances on `\r`, and a `\n` immediately following it is not
+/// counted again.
+///
+/// Chars and their positions are pulled from `source` lazily, one at a time,
+/// and only the ones actually visited are cached — a caller that stops
+/// early (e.g. the streaming `next_token` API bailing after the first
+/// token, or erroring out partway through) never pays to scan the rest of
+/// the source.
+#[derive(Debug, Clone)]
+pub(crate) struct Cursor<'arena> {
+    source: &'arena str,
+    rest: CharIndices<'arena>,
+    chars: Vec<CursorItem>,
+    positions: Vec<Position>,
+    index: usize,
+    line: usize,
+    column: usize,
+    prev_was_cr: bool,
+    exhausted: bool,
+}
+
+impl<'arena> From<&'arena str> for Cursor<'arena> {
+    fn from(source: &'arena str) -> Self {
+        Self {
+            source,
+            rest: source.char_indices(),
+            chars: Vec::new(),
+            positions: Vec::new(),
+            index: 0,
+            line: 0,
+            column: 0,
+            prev_was_cr: false,
+            exhausted: false,
+        }
+    }
+}
+
+impl<'arena> Cursor<'arena> {
+    /// Pulls chars out of `rest` until `chars[index]` is available (or the
+    /// source is exhausted, in which case `positions` gains a final
+    /// end-of-input sentinel). Idempotent past that point.
+    fn ensure(&mut self, index: usize) {
+        while !self.exhausted && self.chars.len() <= index {
+            match self.rest.next() {
+                Some((offset, c)) => {
+                    self.positions
+                        .push(Position::new(self.line, self.column, offset));
+                    self.chars.push((offset, c));
+
+                    if c == '\r' {
+                        self.line += 1;
+                        self.column = 0;
+                        self.prev_was_cr = true;
+                    } else if c == '\n' {
+                        if self.prev_was_cr {
+                            self.prev_was_cr = false;
+                        } else {
+                            self.line += 1;
+                            self.column = 0;
+                        }
+                    } else {
+                        self.column += 1;
+                        self.prev_was_cr = false;
+                    }
+                }
+                None => {
+                    self.positions
+                        .push(Position::new(self.line, self.column, self.source.len()));
+                    self.exhausted = true;
+                }
+            }
+        }
+    }
+
+    pub fn next(&mut self) -> Option<CursorItem> {
+        self.ensure(self.index);
+        let item = self.chars.get(self.index).copied();
+        if item.is_some() {
+            self.index += 1;
+        }
+
+        item
+    }
+
+    pub fn peek(&mut self) -> Option<CursorItem> {
+        self.ensure(self.index);
+        self.chars.get(self.index).copied()
+    }
+
+    pub fn peek_back(&self) -> Option<CursorItem> {
+        self.index
+            .checked_sub(1)
+            .and_then(|i| self.chars.get(i).copied())
+    }
+
+    pub fn back(&mut self) {
+        self.index = self.index.saturating_sub(1);
+    }
+
+    pub fn next_if<F>(&mut self, func: F) -> Option<CursorItem>
+    where
+        F: FnOnce(char) -> bool,
+    {
+        let (i, c) = self.peek()?;
+        if func(c) {
+            self.index += 1;
+            Some((i, c))
+        } else {
+            None
+        }
+    }
+
+    pub fn next_if_is(&mut self, literal: &str) -> bool {
+        let start = self.peek().map_or(self.source.len(), |(i, _)| i);
+        if self.source[start..].starts_with(literal) {
+            self.index += literal.chars().count();
+            self.ensure(self.index);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Byte offset of the next unread character (or end-of-source).
+    pub fn offset(&self) -> usize {
+        self.position().offset
+    }
+
+    /// Line/column/offset of the next unread character (or end-of-source).
+    pub fn position(&self) -> Position {
+        self.positions[self.index]
+    }
+
+    /// Looks up the `Position` of an arbitrary byte offset previously
+    /// handed out by this cursor (e.g. the `start` captured by a lexing
+    /// function before it advanced further). Only ever called with offsets
+    /// the cursor has already visited, or the offset just past the last
+    /// char pulled so far (a token's end), which resolves from the running
+    /// `line`/`column`.
+    pub fn position_for_offset(&self, offset: usize) -> Position {
+        match self.chars.binary_search_by_key(&offset, |&(o, _)| o) {
+            Ok(index) => self.positions[index],
+            Err(index) => self
+                .positions
+                .get(index)
+                .copied()
+                .unwrap_or_else(|| Position::new(self.line, self.column, offset)),
+        }
+    }
+}
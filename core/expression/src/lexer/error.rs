@@ -0,0 +1,42 @@
+use crate::lexer::position::Position;
+use std::fmt;
+
+pub type LexerResult<T> = Result<T, LexerError>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LexerError {
+    UnexpectedEof { symbol: char, position: Position },
+    UnmatchedSymbol { symbol: char, position: Position },
+    UnknownBracket(String),
+    UnknownOperator(String),
+    InvalidEscape { sequence: String, position: Position },
+}
+
+impl fmt::Display for LexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LexerError::UnexpectedEof { symbol, position } => write!(
+                f,
+                "unexpected end of input after '{symbol}' at {}:{}",
+                position.line + 1,
+                position.column + 1
+            ),
+            LexerError::UnmatchedSymbol { symbol, position } => write!(
+                f,
+                "unmatched symbol '{symbol}' at {}:{}",
+                position.line + 1,
+                position.column + 1
+            ),
+            LexerError::UnknownBracket(value) => write!(f, "unknown bracket '{value}'"),
+            LexerError::UnknownOperator(value) => write!(f, "unknown operator '{value}'"),
+            LexerError::InvalidEscape { sequence, position } => write!(
+                f,
+                "invalid escape sequence '{sequence}' at {}:{}",
+                position.line + 1,
+                position.column + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LexerError {}
@@ -1,15 +1,22 @@
 use crate::lexer::codes::{is_token_type, token_type};
 use crate::lexer::cursor::{Cursor, CursorItem};
-use crate::lexer::error::LexerError::{UnexpectedEof, UnmatchedSymbol};
+use crate::lexer::error::LexerError::{InvalidEscape, UnexpectedEof, UnmatchedSymbol};
 use crate::lexer::error::LexerResult;
 use crate::lexer::token::{
-    Bracket, ComparisonOperator, Identifier, LogicalOperator, Operator, Token, TokenKind,
+    Bracket, ComparisonOperator, Identifier, LogicalOperator, NumberRadix, Operator, Token,
+    TokenKind,
 };
 use crate::lexer::{QuotationMark, TemplateString};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use unicode_ident::{is_xid_continue, is_xid_start};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
 
 #[derive(Debug, Default)]
 pub struct Lexer<'arena> {
     tokens: Vec<Token<'arena>>,
+    preserve_comments: bool,
+    scanner: Option<Scanner<'arena>>,
 }
 
 impl<'arena> Lexer<'arena> {
@@ -17,35 +24,72 @@ impl<'arena> Lexer<'arena> {
         Self::default()
     }
 
+    /// When set, `//` and `/* */` comments are kept as `TokenKind::Comment`
+    /// tokens instead of being skipped. Off by default.
+    pub fn with_comments(mut self, preserve_comments: bool) -> Self {
+        self.preserve_comments = preserve_comments;
+        self
+    }
+
+    /// Pulls one token at a time instead of materializing the whole
+    /// source up front, so a parser can stop early without lexing the
+    /// remainder. The scanner is created on first call and reused by
+    /// subsequent calls against the same `source`; pass a different
+    /// `source` to start over. Yields `None` at end of input.
+    pub fn next_token(&mut self, source: &'arena str) -> LexerResult<Option<Token<'arena>>> {
+        let same_source = matches!(&self.scanner, Some(scanner) if std::ptr::eq(scanner.source, source));
+
+        if !same_source {
+            self.scanner = Some(Scanner::new(source, self.preserve_comments));
+        }
+
+        self.scanner.as_mut().unwrap().next_token()
+    }
+
+    /// Thin wrapper over [`Lexer::next_token`] that drains the streaming
+    /// API into a buffer, for callers that want the whole token list.
     pub fn tokenize(&mut self, source: &'arena str) -> LexerResult<&[Token<'arena>]> {
         self.tokens.clear();
+        self.scanner = None;
+
+        while let Some(token) = self.next_token(source)? {
+            self.tokens.push(token);
+        }
 
-        Scanner::new(source, &mut self.tokens).scan()?;
         Ok(&self.tokens)
     }
 }
 
-struct Scanner<'arena, 'self_ref> {
+#[derive(Debug)]
+struct Scanner<'arena> {
     cursor: Cursor<'arena>,
-    tokens: &'self_ref mut Vec<Token<'arena>>,
     source: &'arena str,
+    preserve_comments: bool,
+    pending: VecDeque<Token<'arena>>,
 }
 
-impl<'arena, 'self_ref> Scanner<'arena, 'self_ref> {
-    pub fn new(source: &'arena str, tokens: &'self_ref mut Vec<Token<'arena>>) -> Self {
+impl<'arena> Scanner<'arena> {
+    pub fn new(source: &'arena str, preserve_comments: bool) -> Self {
         Self {
             cursor: Cursor::from(source),
             source,
-            tokens,
+            preserve_comments,
+            pending: VecDeque::new(),
         }
     }
 
-    pub fn scan(&mut self) -> LexerResult<()> {
-        while let Some(cursor_item) = self.cursor.peek() {
+    pub fn next_token(&mut self) -> LexerResult<Option<Token<'arena>>> {
+        loop {
+            if let Some(token) = self.pending.pop_front() {
+                return Ok(Some(token));
+            }
+
+            let Some(cursor_item) = self.cursor.peek() else {
+                return Ok(None);
+            };
+
             self.scan_cursor_item(cursor_item)?;
         }
-
-        Ok(())
     }
 
     pub(crate) fn scan_cursor_item(&mut self, cursor_item: CursorItem) -> LexerResult<()> {
@@ -66,36 +110,133 @@ impl<'arena, 'self_ref> Scanner<'arena, 'self_ref> {
             '`' => self.template_string(),
             '.' => self.dot(),
             token_type!("alpha") => self.identifier(),
+            _ if is_xid_start(s) => self.identifier(),
             _ => Err(UnmatchedSymbol {
                 symbol: s,
-                position: i,
+                position: self.cursor.position_for_offset(i),
             }),
         }
     }
 
-    fn next(&self) -> LexerResult<CursorItem> {
+    fn next(&mut self) -> LexerResult<CursorItem> {
         self.cursor.next().ok_or_else(|| {
             let (a, b) = self.cursor.peek_back().unwrap_or((0, ' '));
 
             UnexpectedEof {
                 symbol: b,
-                position: a,
+                position: self.cursor.position_for_offset(a),
             }
         })
     }
 
+    /// Builds a `Token`, resolving the rich `start`/`end` positions for the
+    /// given byte span from the cursor's position table.
+    fn token<V>(&self, kind: TokenKind<'arena>, span: (usize, usize), value: V) -> Token<'arena>
+    where
+        V: Into<Cow<'arena, str>>,
+    {
+        Token {
+            kind,
+            span,
+            start: self.cursor.position_for_offset(span.0),
+            end: self.cursor.position_for_offset(span.1),
+            value: value.into(),
+        }
+    }
+
     fn push(&mut self, token: Token<'arena>) {
-        self.tokens.push(token);
+        self.pending.push_back(token);
+    }
+
+    /// Decodes `` \n \t \r \\ \' \" \0 \` \$ ``, `\xNN` and `\u{XXXX}` escapes in a
+    /// raw source slice. `start` is the absolute byte offset of `raw` within
+    /// `self.source`, used to resolve error positions. Returns a borrow when
+    /// no escapes were present so unescaped literals stay zero-copy.
+    fn unescape(&self, start: usize, raw: &'arena str) -> LexerResult<Cow<'arena, str>> {
+        if !raw.contains('\\') {
+            return Ok(Cow::Borrowed(raw));
+        }
+
+        let mut out = String::with_capacity(raw.len());
+        let mut chars = raw.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            if c != '\\' {
+                out.push(c);
+                continue;
+            }
+
+            let invalid = |sequence: &str, offset: usize| InvalidEscape {
+                sequence: sequence.to_string(),
+                position: self.cursor.position_for_offset(start + offset),
+            };
+
+            let (_, escape) = chars.next().ok_or_else(|| UnexpectedEof {
+                symbol: '\\',
+                position: self.cursor.position_for_offset(start + i),
+            })?;
+
+            match escape {
+                'n' => out.push('\n'),
+                't' => out.push('\t'),
+                'r' => out.push('\r'),
+                '\\' => out.push('\\'),
+                '\'' => out.push('\''),
+                '"' => out.push('"'),
+                '0' => out.push('\0'),
+                '`' => out.push('`'),
+                '$' => out.push('$'),
+                'x' => {
+                    let hex: String = chars.by_ref().take(2).map(|(_, c)| c).collect();
+                    if hex.len() != 2 {
+                        return Err(invalid(&format!("\\x{hex}"), i));
+                    }
+
+                    let byte = u8::from_str_radix(&hex, 16)
+                        .map_err(|_| invalid(&format!("\\x{hex}"), i))?;
+                    out.push(byte as char);
+                }
+                'u' => {
+                    if chars.next_if(|&(_, c)| c == '{').is_none() {
+                        return Err(invalid("\\u", i));
+                    }
+
+                    let mut hex = String::new();
+                    loop {
+                        match chars.next() {
+                            Some((_, '}')) => break,
+                            Some((_, h)) => hex.push(h),
+                            None => {
+                                return Err(UnexpectedEof {
+                                    symbol: 'u',
+                                    position: self.cursor.position_for_offset(start + i),
+                                })
+                            }
+                        }
+                    }
+
+                    let code_point = u32::from_str_radix(&hex, 16)
+                        .map_err(|_| invalid(&format!("\\u{{{hex}}}"), i))?;
+                    let decoded =
+                        char::from_u32(code_point).ok_or_else(|| invalid(&format!("\\u{{{hex}}}"), i))?;
+                    out.push(decoded);
+                }
+                other => return Err(invalid(&format!("\\{other}"), i)),
+            }
+        }
+
+        Ok(Cow::Owned(out))
     }
 
     fn template_string(&mut self) -> LexerResult<()> {
         let (start, _) = self.next()?;
 
-        self.tokens.push(Token {
-            kind: TokenKind::QuotationMark(QuotationMark::Backtick),
-            span: (start, start + 1),
-            value: QuotationMark::Backtick.into(),
-        });
+        let token = self.token(
+            TokenKind::QuotationMark(QuotationMark::Backtick),
+            (start, start + 1),
+            Cow::Borrowed(QuotationMark::Backtick.into()),
+        );
+        self.push(token);
 
         let mut in_expression = false;
         let mut str_start = start + 1;
@@ -105,44 +246,46 @@ impl<'arena, 'self_ref> Scanner<'arena, 'self_ref> {
             match (c, in_expression) {
                 ('`', _) => {
                     if str_start < e {
-                        self.tokens.push(Token {
-                            kind: TokenKind::Literal,
-                            span: (str_start, e),
-                            value: &self.source[str_start..e],
-                        });
+                        let literal = self.unescape(str_start, &self.source[str_start..e])?;
+                        let token = self.token(TokenKind::Literal, (str_start, e), literal);
+                        self.push(token);
                     }
 
-                    self.tokens.push(Token {
-                        kind: TokenKind::QuotationMark(QuotationMark::Backtick),
-                        span: (e, e + 1),
-                        value: QuotationMark::Backtick.into(),
-                    });
+                    let token = self.token(
+                        TokenKind::QuotationMark(QuotationMark::Backtick),
+                        (e, e + 1),
+                        Cow::Borrowed(QuotationMark::Backtick.into()),
+                    );
+                    self.push(token);
 
                     break;
                 }
+                ('\\', false) => {
+                    self.next()?;
+                }
                 ('$', false) => {
                     in_expression = self.cursor.next_if_is("{");
                     if in_expression {
-                        self.tokens.push(Token {
-                            kind: TokenKind::Literal,
-                            span: (str_start, e),
-                            value: &self.source[str_start..e],
-                        });
-
-                        self.tokens.push(Token {
-                            kind: TokenKind::TemplateString(TemplateString::ExpressionStart),
-                            span: (e, e + 2),
-                            value: TemplateString::ExpressionStart.into(),
-                        });
+                        let literal = self.unescape(str_start, &self.source[str_start..e])?;
+                        let token = self.token(TokenKind::Literal, (str_start, e), literal);
+                        self.push(token);
+
+                        let token = self.token(
+                            TokenKind::TemplateString(TemplateString::ExpressionStart),
+                            (e, e + 2),
+                            Cow::Borrowed(TemplateString::ExpressionStart.into()),
+                        );
+                        self.push(token);
                     }
                 }
                 ('}', true) => {
                     in_expression = false;
-                    self.tokens.push(Token {
-                        kind: TokenKind::TemplateString(TemplateString::ExpressionEnd),
-                        span: (str_start, e),
-                        value: TemplateString::ExpressionEnd.into(),
-                    });
+                    let token = self.token(
+                        TokenKind::TemplateString(TemplateString::ExpressionEnd),
+                        (str_start, e),
+                        Cow::Borrowed(TemplateString::ExpressionEnd.into()),
+                    );
+                    self.push(token);
 
                     str_start = e + 1;
                 }
@@ -165,35 +308,84 @@ impl<'arena, 'self_ref> Scanner<'arena, 'self_ref> {
 
         loop {
             let (e, c) = self.next()?;
+            if c == '\\' {
+                self.next()?;
+                continue;
+            }
             if c == opener {
                 end = e;
                 break;
             }
         }
 
-        self.push(Token {
-            kind: TokenKind::QuotationMark(quote_kind),
-            span: (start, start + 1),
-            value: quote_kind.into(),
-        });
+        let token = self.token(
+            TokenKind::QuotationMark(quote_kind),
+            (start, start + 1),
+            Cow::Borrowed(quote_kind.into()),
+        );
+        self.push(token);
 
-        self.push(Token {
-            kind: TokenKind::Literal,
-            span: (start + 1, end),
-            value: &self.source[start + 1..end],
-        });
+        let literal = self.unescape(start + 1, &self.source[start + 1..end])?;
+        let token = self.token(TokenKind::Literal, (start + 1, end), literal);
+        self.push(token);
 
-        self.push(Token {
-            kind: TokenKind::QuotationMark(quote_kind),
-            span: (end, end + 1),
-            value: quote_kind.into(),
-        });
+        let token = self.token(
+            TokenKind::QuotationMark(quote_kind),
+            (end, end + 1),
+            Cow::Borrowed(quote_kind.into()),
+        );
+        self.push(token);
 
         Ok(())
     }
 
     fn number(&mut self) -> LexerResult<()> {
-        let (start, _) = self.next()?;
+        let (start, first) = self.next()?;
+
+        if first == '0' {
+            if let Some((_, marker)) = self.cursor.peek() {
+                let prefixed = match marker {
+                    'x' | 'X' => Some((NumberRadix::Hexadecimal, 16)),
+                    'b' | 'B' => Some((NumberRadix::Binary, 2)),
+                    'o' | 'O' => Some((NumberRadix::Octal, 8)),
+                    _ => None,
+                };
+
+                if let Some((kind, radix)) = prefixed {
+                    return self.radix_number(start, radix, kind);
+                }
+            }
+        }
+
+        self.decimal_number(start)
+    }
+
+    fn radix_number(&mut self, start: usize, radix: u32, kind: NumberRadix) -> LexerResult<()> {
+        let (marker, marker_char) = self.next()?;
+        let mut end = marker;
+        let mut has_digits = false;
+
+        while let Some((e, c)) = self.cursor.next_if(|c| c == '_' || c.is_digit(radix)) {
+            if c != '_' {
+                has_digits = true;
+            }
+            end = e;
+        }
+
+        if !has_digits {
+            return Err(UnmatchedSymbol {
+                symbol: marker_char,
+                position: self.cursor.position_for_offset(marker),
+            });
+        }
+
+        let token = self.token(TokenKind::Number(kind), (start, end + 1), &self.source[start..=end]);
+        self.push(token);
+
+        Ok(())
+    }
+
+    fn decimal_number(&mut self, start: usize) -> LexerResult<()> {
         let mut end = start;
         let mut fractal = false;
 
@@ -220,24 +412,54 @@ impl<'arena, 'self_ref> Scanner<'arena, 'self_ref> {
             end = e;
         }
 
-        self.push(Token {
-            kind: TokenKind::Number,
-            span: (start, end + 1),
-            value: &self.source[start..=end],
-        });
+        if matches!(self.cursor.peek(), Some((_, 'e' | 'E'))) {
+            end = self.exponent()?;
+        }
+
+        let token = self.token(
+            TokenKind::Number(NumberRadix::Decimal),
+            (start, end + 1),
+            &self.source[start..=end],
+        );
+        self.push(token);
 
         Ok(())
     }
 
+    fn exponent(&mut self) -> LexerResult<usize> {
+        let (marker, marker_char) = self.next()?;
+        let mut end = marker;
+
+        if let Some((e, _)) = self.cursor.next_if(|c| c == '+' || c == '-') {
+            end = e;
+        }
+
+        let mut has_digits = false;
+        while let Some((e, _)) = self.cursor.next_if(|c| is_token_type!(c, "digit")) {
+            has_digits = true;
+            end = e;
+        }
+
+        if !has_digits {
+            return Err(UnmatchedSymbol {
+                symbol: marker_char,
+                position: self.cursor.position_for_offset(marker),
+            });
+        }
+
+        Ok(end)
+    }
+
     fn bracket(&mut self) -> LexerResult<()> {
         let (start, _) = self.next()?;
 
         let value = &self.source[start..=start];
-        self.push(Token {
-            kind: TokenKind::Bracket(Bracket::try_from(value)?),
-            span: (start, start + 1),
+        let token = self.token(
+            TokenKind::Bracket(Bracket::try_from(value)?),
+            (start, start + 1),
             value,
-        });
+        );
+        self.push(token);
 
         Ok(())
     }
@@ -251,11 +473,12 @@ impl<'arena, 'self_ref> Scanner<'arena, 'self_ref> {
         }
 
         let value = &self.source[start..=end];
-        self.push(Token {
-            kind: TokenKind::Operator(Operator::try_from(value)?),
-            span: (start, end + 1),
+        let token = self.token(
+            TokenKind::Operator(Operator::try_from(value)?),
+            (start, end + 1),
             value,
-        });
+        );
+        self.push(token);
 
         Ok(())
     }
@@ -269,11 +492,12 @@ impl<'arena, 'self_ref> Scanner<'arena, 'self_ref> {
         }
 
         let value = &self.source[start..=end];
-        self.push(Token {
-            kind: TokenKind::Operator(Operator::try_from(value)?),
-            span: (start, end + 1),
+        let token = self.token(
+            TokenKind::Operator(Operator::try_from(value)?),
+            (start, end + 1),
             value,
-        });
+        );
+        self.push(token);
 
         Ok(())
     }
@@ -289,93 +513,317 @@ impl<'arena, 'self_ref> Scanner<'arena, 'self_ref> {
         }
 
         let value = &self.source[start..=end];
-        self.push(Token {
-            kind,
-            value,
-            span: (start, end + 1),
-        });
+        let token = self.token(kind, (start, end + 1), value);
+        self.push(token);
 
         Ok(())
     }
 
     fn operator(&mut self) -> LexerResult<()> {
-        let (start, _) = self.next()?;
+        let (start, c) = self.next()?;
+
+        if c == '/' {
+            if self.cursor.next_if(|c| c == '/').is_some() {
+                return self.line_comment(start);
+            }
+
+            if self.cursor.next_if(|c| c == '*').is_some() {
+                return self.block_comment(start);
+            }
+        }
 
         let value = &self.source[start..=start];
-        self.push(Token {
-            kind: TokenKind::Operator(Operator::try_from(value)?),
-            span: (start, start + 1),
+        let token = self.token(
+            TokenKind::Operator(Operator::try_from(value)?),
+            (start, start + 1),
             value,
-        });
+        );
+        self.push(token);
+
+        Ok(())
+    }
+
+    fn line_comment(&mut self, start: usize) -> LexerResult<()> {
+        let mut end = start + 1;
+
+        while let Some((e, _)) = self.cursor.next_if(|c| c != '\n') {
+            end = e;
+        }
+
+        if self.preserve_comments {
+            let token = self.token(TokenKind::Comment, (start, end + 1), &self.source[start..=end]);
+            self.push(token);
+        }
+
+        Ok(())
+    }
+
+    fn block_comment(&mut self, start: usize) -> LexerResult<()> {
+        let end;
+
+        loop {
+            let (e, c) = self.next()?;
+
+            if c == '*' && self.cursor.next_if(|c| c == '/').is_some() {
+                end = e + 1;
+                break;
+            }
+        }
+
+        if self.preserve_comments {
+            let token = self.token(TokenKind::Comment, (start, end + 1), &self.source[start..=end]);
+            self.push(token);
+        }
 
         Ok(())
     }
 
     fn not(&mut self, start: usize) -> LexerResult<()> {
         if self.cursor.next_if_is(" in ") {
-            let end = self.cursor.position();
-
-            self.push(Token {
-                kind: TokenKind::Operator(Operator::Comparison(ComparisonOperator::NotIn)),
-                span: (start, end - 1),
-                value: "not in",
-            })
+            let end = self.cursor.offset();
+
+            let token = self.token(
+                TokenKind::Operator(Operator::Comparison(ComparisonOperator::NotIn)),
+                (start, end - 1),
+                "not in",
+            );
+            self.push(token)
         } else {
-            let end = self.cursor.position();
-
-            self.push(Token {
-                kind: TokenKind::Operator(Operator::Logical(LogicalOperator::Not)),
-                span: (start, end),
-                value: "not",
-            })
+            let end = self.cursor.offset();
+
+            let token = self.token(
+                TokenKind::Operator(Operator::Logical(LogicalOperator::Not)),
+                (start, end),
+                "not",
+            );
+            self.push(token)
         }
 
         Ok(())
     }
 
     fn identifier(&mut self) -> LexerResult<()> {
-        let (start, _) = self.next()?;
-        let mut end = start;
+        let (start, first) = self.next()?;
+        let mut end = start + first.len_utf8();
 
-        while let Some((e, _)) = self.cursor.next_if(|c| is_token_type!(c, "alphanumeric")) {
-            end = e;
+        while let Some((e, c)) = self.cursor.next_if(is_xid_continue) {
+            end = e + c.len_utf8();
         }
 
-        let value = &self.source[start..=end];
-        match value {
-            "and" => self.push(Token {
-                kind: TokenKind::Operator(Operator::Logical(LogicalOperator::And)),
-                span: (start, end + 1),
-                value,
-            }),
-            "or" => self.push(Token {
-                kind: TokenKind::Operator(Operator::Logical(LogicalOperator::Or)),
-                span: (start, end + 1),
-                value,
-            }),
-            "in" => self.push(Token {
-                kind: TokenKind::Operator(Operator::Comparison(ComparisonOperator::In)),
-                span: (start, end + 1),
-                value,
-            }),
-            "true" => self.push(Token {
-                kind: TokenKind::Boolean(true),
-                span: (start, end + 1),
-                value,
-            }),
-            "false" => self.push(Token {
-                kind: TokenKind::Boolean(false),
-                span: (start, end + 1),
-                value,
-            }),
+        let raw = &self.source[start..end];
+        let normalized: Cow<'arena, str> = if is_nfc(raw) {
+            Cow::Borrowed(raw)
+        } else {
+            Cow::Owned(raw.nfc().collect())
+        };
+
+        match normalized.as_ref() {
+            "and" => {
+                let token = self.token(
+                    TokenKind::Operator(Operator::Logical(LogicalOperator::And)),
+                    (start, end),
+                    normalized,
+                );
+                self.push(token)
+            }
+            "or" => {
+                let token = self.token(
+                    TokenKind::Operator(Operator::Logical(LogicalOperator::Or)),
+                    (start, end),
+                    normalized,
+                );
+                self.push(token)
+            }
+            "in" => {
+                let token = self.token(
+                    TokenKind::Operator(Operator::Comparison(ComparisonOperator::In)),
+                    (start, end),
+                    normalized,
+                );
+                self.push(token)
+            }
+            "true" => {
+                let token = self.token(TokenKind::Boolean(true), (start, end), normalized);
+                self.push(token)
+            }
+            "false" => {
+                let token = self.token(TokenKind::Boolean(false), (start, end), normalized);
+                self.push(token)
+            }
             "not" => self.not(start)?,
-            _ => self.push(Token {
-                kind: TokenKind::Identifier(Identifier::from(value)),
-                span: (start, end + 1),
-                value,
-            }),
+            _ => {
+                let token = self.token(
+                    TokenKind::Identifier(Identifier::from(normalized)),
+                    (start, end),
+                    raw,
+                );
+                self.push(token)
+            }
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_track_lines_and_treat_crlf_as_one_break() {
+        let mut lexer = Lexer::new();
+        let tokens = lexer.tokenize("a\nbb\r\nccc").unwrap();
+
+        assert_eq!(tokens[0].start.line, 0);
+        assert_eq!(tokens[0].start.column, 0);
+        assert_eq!(tokens[0].span, (0, 1));
+
+        assert_eq!(tokens[1].start.line, 1);
+        assert_eq!(tokens[1].start.column, 0);
+        assert_eq!(tokens[1].span, (2, 4));
+
+        assert_eq!(tokens[2].start.line, 2);
+        assert_eq!(tokens[2].start.column, 0);
+        assert_eq!(tokens[2].span, (6, 9));
+    }
+
+    #[test]
+    fn next_token_yields_tokens_one_at_a_time() {
+        let mut lexer = Lexer::new();
+        let source = "1 2 3";
+
+        let first = lexer.next_token(source).unwrap().unwrap();
+        assert_eq!(first.value.as_ref(), "1");
+
+        let second = lexer.next_token(source).unwrap().unwrap();
+        assert_eq!(second.value.as_ref(), "2");
+
+        let third = lexer.next_token(source).unwrap().unwrap();
+        assert_eq!(third.value.as_ref(), "3");
+
+        assert!(lexer.next_token(source).unwrap().is_none());
+    }
+
+    #[test]
+    fn identifier_is_normalized_to_nfc() {
+        let mut lexer = Lexer::new();
+        // "e" followed by a combining acute accent (NFD) rather than the
+        // precomposed "é" (NFC).
+        let tokens = lexer.tokenize("e\u{0301} and x").unwrap();
+
+        match &tokens[0].kind {
+            TokenKind::Identifier(Identifier(normalized)) => {
+                assert_eq!(normalized.as_ref(), "\u{e9}");
+            }
+            other => panic!("expected an identifier, got {other:?}"),
+        }
+
+        assert_eq!(tokens[1].kind, TokenKind::Operator(Operator::Logical(LogicalOperator::And)));
+    }
+
+    #[test]
+    fn extended_numeric_literals_lex_to_expected_radix_and_value() {
+        let mut lexer = Lexer::new();
+
+        let tokens = lexer.tokenize("0b1010").unwrap();
+        assert_eq!(tokens[0].kind, TokenKind::Number(NumberRadix::Binary));
+        assert_eq!(tokens[0].value.as_ref(), "0b1010");
+
+        let tokens = lexer.tokenize("0o17").unwrap();
+        assert_eq!(tokens[0].kind, TokenKind::Number(NumberRadix::Octal));
+        assert_eq!(tokens[0].value.as_ref(), "0o17");
+
+        let tokens = lexer.tokenize("0xFF").unwrap();
+        assert_eq!(tokens[0].kind, TokenKind::Number(NumberRadix::Hexadecimal));
+        assert_eq!(tokens[0].value.as_ref(), "0xFF");
+
+        let tokens = lexer.tokenize("1.5e-3").unwrap();
+        assert_eq!(tokens[0].kind, TokenKind::Number(NumberRadix::Decimal));
+        assert_eq!(tokens[0].value.as_ref(), "1.5e-3");
+    }
+
+    #[test]
+    fn double_quoted_string_decodes_escapes() {
+        let mut lexer = Lexer::new();
+        let tokens = lexer.tokenize(r#""a\nb""#).unwrap();
+
+        let literal = tokens
+            .iter()
+            .find(|t| t.kind == TokenKind::Literal)
+            .unwrap();
+        assert_eq!(literal.value.as_ref(), "a\nb");
+    }
+
+    #[test]
+    fn single_quoted_string_decodes_escaped_quote() {
+        let mut lexer = Lexer::new();
+        let tokens = lexer.tokenize(r"'it\'s'").unwrap();
+
+        let literal = tokens
+            .iter()
+            .find(|t| t.kind == TokenKind::Literal)
+            .unwrap();
+        assert_eq!(literal.value.as_ref(), "it's");
+    }
+
+    #[test]
+    fn line_and_block_comments_are_skipped_by_default() {
+        let mut lexer = Lexer::new();
+        let tokens = lexer.tokenize("1 // comment\n/* block */ 2").unwrap();
+
+        assert_eq!(tokens.len(), 2);
+        assert!(tokens.iter().all(|t| !matches!(t.kind, TokenKind::Comment)));
+    }
+
+    #[test]
+    fn comments_are_preserved_when_requested() {
+        let mut lexer = Lexer::new().with_comments(true);
+        let tokens = lexer.tokenize("// hi\n1").unwrap();
+
+        assert_eq!(tokens[0].kind, TokenKind::Comment);
+        assert_eq!(tokens[0].value.as_ref(), "// hi");
+    }
+
+    #[test]
+    fn unterminated_block_comment_reports_eof() {
+        let mut lexer = Lexer::new();
+        let err = lexer.tokenize("/* never closed").unwrap_err();
+        assert!(matches!(err, UnexpectedEof { .. }));
+    }
+
+    #[test]
+    fn truncated_hex_escape_is_rejected() {
+        let mut lexer = Lexer::new();
+        let err = lexer.tokenize("\"\\xA\"").unwrap_err();
+        assert!(matches!(err, InvalidEscape { .. }));
+    }
+
+    #[test]
+    fn out_of_range_unicode_escape_is_rejected() {
+        let mut lexer = Lexer::new();
+        let err = lexer.tokenize("\"\\u{110000}\"").unwrap_err();
+        assert!(matches!(err, InvalidEscape { .. }));
+    }
+
+    #[test]
+    fn hex_literal_requires_digits() {
+        let mut lexer = Lexer::new();
+        let err = lexer.tokenize("0x").unwrap_err();
+        assert!(matches!(err, UnmatchedSymbol { .. }));
+    }
+
+    #[test]
+    fn trailing_bare_exponent_is_rejected() {
+        let mut lexer = Lexer::new();
+        let err = lexer.tokenize("1e").unwrap_err();
+        assert!(matches!(err, UnmatchedSymbol { .. }));
+    }
+
+    #[test]
+    fn identifier_ending_in_multi_byte_char_does_not_panic() {
+        let mut lexer = Lexer::new();
+        let tokens = lexer.tokenize("привет").unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].value.as_ref(), "привет");
+    }
+}
@@ -0,0 +1,15 @@
+mod codes;
+mod cursor;
+mod error;
+#[allow(clippy::module_inception)]
+mod lexer;
+mod position;
+mod token;
+
+pub use error::{LexerError, LexerResult};
+pub use lexer::Lexer;
+pub use position::Position;
+pub use token::{
+    Bracket, ComparisonOperator, Identifier, LogicalOperator, NumberRadix, Operator,
+    QuotationMark, TemplateString, Token, TokenKind,
+};
@@ -0,0 +1,18 @@
+/// A human-readable location in the source, paired with the byte offset
+/// `Cursor` already tracks so existing offset-based spans keep working.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+impl Position {
+    pub fn new(line: usize, column: usize, offset: usize) -> Self {
+        Self {
+            line,
+            column,
+            offset,
+        }
+    }
+}
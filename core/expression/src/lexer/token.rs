@@ -0,0 +1,170 @@
+use crate::lexer::error::LexerError;
+use crate::lexer::position::Position;
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token<'arena> {
+    pub kind: TokenKind<'arena>,
+    /// Flat byte offsets, kept around for consumers that only need a slice
+    /// of the source.
+    pub span: (usize, usize),
+    pub start: Position,
+    pub end: Position,
+    /// Borrowed whenever the token is a verbatim slice of the source;
+    /// owned when it had to be rewritten (e.g. a string literal with
+    /// escape sequences).
+    pub value: Cow<'arena, str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenKind<'arena> {
+    Literal,
+    Number(NumberRadix),
+    Boolean(bool),
+    Identifier(Identifier<'arena>),
+    Operator(Operator),
+    Bracket(Bracket),
+    QuotationMark(QuotationMark),
+    TemplateString(TemplateString),
+    /// Only produced when the lexer is configured to preserve comments;
+    /// skipped (and never pushed) otherwise.
+    Comment,
+}
+
+/// The base a `TokenKind::Number` literal was written in, so the
+/// parser/interpreter can decode its digits with the right radix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberRadix {
+    Decimal,
+    Hexadecimal,
+    Octal,
+    Binary,
+}
+
+/// Holds the NFC-normalized identifier text. Borrowed when the source was
+/// already normalized (the common case); owned when normalization had to
+/// rewrite it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identifier<'arena>(pub Cow<'arena, str>);
+
+impl<'arena> From<Cow<'arena, str>> for Identifier<'arena> {
+    fn from(value: Cow<'arena, str>) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bracket {
+    LeftParenthesis,
+    RightParenthesis,
+    LeftSquareBracket,
+    RightSquareBracket,
+    LeftCurlyBracket,
+    RightCurlyBracket,
+}
+
+impl TryFrom<&str> for Bracket {
+    type Error = LexerError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "(" => Bracket::LeftParenthesis,
+            ")" => Bracket::RightParenthesis,
+            "[" => Bracket::LeftSquareBracket,
+            "]" => Bracket::RightSquareBracket,
+            "{" => Bracket::LeftCurlyBracket,
+            "}" => Bracket::RightCurlyBracket,
+            other => return Err(LexerError::UnknownBracket(other.to_string())),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+    Modulo,
+    Dot,
+    Range,
+    QuestionMark,
+    Logical(LogicalOperator),
+    Comparison(ComparisonOperator),
+}
+
+impl TryFrom<&str> for Operator {
+    type Error = LexerError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "+" => Operator::Plus,
+            "-" => Operator::Minus,
+            "*" => Operator::Multiply,
+            "/" => Operator::Divide,
+            "%" => Operator::Modulo,
+            "." => Operator::Dot,
+            ".." => Operator::Range,
+            "!" => Operator::Logical(LogicalOperator::Not),
+            "<" => Operator::Comparison(ComparisonOperator::LessThan),
+            "<=" => Operator::Comparison(ComparisonOperator::LessThanOrEqual),
+            ">" => Operator::Comparison(ComparisonOperator::GreaterThan),
+            ">=" => Operator::Comparison(ComparisonOperator::GreaterThanOrEqual),
+            "==" => Operator::Comparison(ComparisonOperator::Equal),
+            "!=" => Operator::Comparison(ComparisonOperator::NotEqual),
+            other => return Err(LexerError::UnknownOperator(other.to_string())),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogicalOperator {
+    And,
+    Or,
+    Not,
+    NullishCoalescing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    In,
+    NotIn,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotationMark {
+    SingleQuote,
+    DoubleQuote,
+    Backtick,
+}
+
+impl From<QuotationMark> for &'static str {
+    fn from(value: QuotationMark) -> Self {
+        match value {
+            QuotationMark::SingleQuote => "'",
+            QuotationMark::DoubleQuote => "\"",
+            QuotationMark::Backtick => "`",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateString {
+    ExpressionStart,
+    ExpressionEnd,
+}
+
+impl From<TemplateString> for &'static str {
+    fn from(value: TemplateString) -> Self {
+        match value {
+            TemplateString::ExpressionStart => "${",
+            TemplateString::ExpressionEnd => "}",
+        }
+    }
+}